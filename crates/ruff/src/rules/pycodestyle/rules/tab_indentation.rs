@@ -1,6 +1,6 @@
 use ruff_text_size::{TextLen, TextRange, TextSize};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{AlwaysAutofixableViolation, Diagnostic, Edit, Fix};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_ast::newlines::Line;
 use ruff_python_ast::whitespace::leading_space;
@@ -34,39 +34,643 @@ use ruff_python_ast::whitespace::leading_space;
 #[violation]
 pub struct TabIndentation;
 
-impl Violation for TabIndentation {
+impl AlwaysAutofixableViolation for TabIndentation {
     #[derive_message_formats]
     fn message(&self) -> String {
         format!("Indentation contains tabs")
     }
+
+    fn autofix_title(&self) -> String {
+        "Replace tabs with spaces".to_string()
+    }
 }
 
-/// W191
-pub(crate) fn tab_indentation(line: &Line, string_ranges: &[TextRange]) -> Option<Diagnostic> {
-    let indent = leading_space(line);
-    if let Some(tab_index) = indent.find('\t') {
-        let tab_offset = line.start() + TextSize::try_from(tab_index).unwrap();
+/// ## What it does
+/// Checks for the presence of spaces in indentation, in files where the
+/// `indent-style` setting is configured to `tabs`.
+///
+/// ## Why is this bad?
+/// When a project enforces tab-based indentation (for consistency with an
+/// existing tab-indented codebase, for example), lines indented with spaces
+/// are inconsistent with the rest of the file.
+///
+/// ## Example - Where `--->` represents a tab, and `indent-style` is `tabs`:
+/// ```python
+/// def foo(x):
+///     return x * 2
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def foo(x):
+/// --->return x * 2
+/// ```
+#[violation]
+pub struct SpaceIndentation;
+
+impl AlwaysAutofixableViolation for SpaceIndentation {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Indentation contains spaces, but the enforced indent-style is tabs")
+    }
+
+    fn autofix_title(&self) -> String {
+        "Replace spaces with tabs".to_string()
+    }
+}
+
+/// ## What it does
+/// Checks for indentation that mixes tabs and spaces within a single line.
+///
+/// ## Why is this bad?
+/// [PEP 8] notes:
+///
+/// > Python disallows mixing tabs and spaces for indentation.
+///
+/// Unlike a line indented solely with tabs, a line whose indentation switches
+/// between spaces and tabs (or vice versa) is the case Python's tokenizer
+/// actually rejects with a `TabError`, since the interpretation of the
+/// indentation depends on the tab size in use.
+///
+/// ## Example - Where `--->` represents a tab:
+/// ```python
+/// if True:
+///     --->pass
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if True:
+///     pass
+/// ```
+///
+/// [PEP 8]: https://peps.python.org/pep-0008/#tabs-or-spaces
+#[violation]
+pub struct MixedSpacesAndTabs;
+
+impl AlwaysAutofixableViolation for MixedSpacesAndTabs {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Indentation contains mixed spaces and tabs")
+    }
+
+    fn autofix_title(&self) -> String {
+        "Normalize indentation to a single style".to_string()
+    }
+}
+
+/// The indentation style enforced by the `indent-style` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndentationStyle {
+    /// Enforce spaces for indentation, flagging any tabs (`TabIndentation`, the default).
+    #[default]
+    Spaces,
+    /// Enforce tabs for indentation, flagging any spaces (`SpaceIndentation`).
+    Tabs,
+    /// Don't enforce a fixed style; instead, detect the file's dominant indentation via
+    /// [`detect_indent_style`] and only flag lines that deviate from it.
+    Detect,
+}
+
+/// A file's indentation style, either configured explicitly or detected from its contents.
+///
+/// Unlike [`IndentationStyle`], which is a user-facing on/off/detect setting, this type is the
+/// single source of truth for indent semantics: a concrete style, including the space width
+/// when relevant, that a given line's indentation can be compared against, expanded to, or
+/// contracted to. `tab_indentation`, `space_indentation`, and `mixed_tabs_and_spaces` all
+/// consume it rather than matching on `\t` and ` ` themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndentStyle {
+    Tabs,
+    Spaces(u8),
+}
+
+impl Default for IndentStyle {
+    fn default() -> Self {
+        IndentStyle::Spaces(4)
+    }
+}
+
+impl IndentStyle {
+    /// Derive an `IndentStyle` from a sample of leading whitespace, e.g. a single line's indent,
+    /// or one level of a file's detected indentation.
+    pub fn from_str(sample: &str) -> Self {
+        if sample.contains('\t') {
+            IndentStyle::Tabs
+        } else {
+            IndentStyle::Spaces(sample.len().clamp(1, 8) as u8)
+        }
+    }
+
+    /// Rewrite `indent`'s leading whitespace to this style: expanding tabs into
+    /// column-aligned spaces at this style's own width when `self` is `Spaces`, or
+    /// contracting `tab_width`-wide groups of spaces into tabs when `self` is `Tabs`.
+    fn normalize(self, indent: &str, tab_width: u8) -> String {
+        match self {
+            IndentStyle::Spaces(width) => expand_indent(indent, width),
+            IndentStyle::Tabs => contract_indent(indent, tab_width),
+        }
+    }
+
+    /// Return the byte offset of the first character in `indent` that doesn't belong to this
+    /// style — a tab when `self` is `Spaces`, or a space when `self` is `Tabs` — if any.
+    fn first_deviation(self, indent: &str) -> Option<usize> {
+        let unexpected = match self {
+            IndentStyle::Spaces(_) => '\t',
+            IndentStyle::Tabs => ' ',
+        };
+        indent.find(unexpected)
+    }
+}
+
+/// Histogram the leading-whitespace delta between each indent and the previous one, as
+/// described by [`detect_indent_style`]. Split out as a pure function over plain `&str`
+/// indents (rather than `&[Line]`) so the histogram/tie-break logic is directly testable
+/// without needing to construct a file's lines.
+///
+/// Each line is paired with whether it's genuinely blank. Blank lines carry no indentation of
+/// their own, so they're skipped rather than folded into the delta computation — otherwise the
+/// line after a blank line would have its *entire* indent compared against `""`, undercounting
+/// its true nesting depth and introducing a bogus vote for whatever width it happens to have.
+/// This can't be inferred from the indent string alone: a line at column 0 (e.g. a top-level
+/// `def`) also has a `""` indent, but unlike a blank line it's a real, zero-depth line whose
+/// `previous_indent` *should* reset, so the caller must tell us which is which.
+fn histogram_indent_style<'a>(
+    lines: impl Iterator<Item = (bool, &'a str)>,
+    default: IndentStyle,
+) -> IndentStyle {
+    let mut votes: [u32; 9] = [0; 9];
+    let mut tab_votes = 0u32;
+
+    let mut previous_indent = "";
+    for (is_blank, indent) in lines {
+        if is_blank {
+            continue;
+        }
+
+        if let Some(delta) = indent.strip_prefix(previous_indent) {
+            let is_pure_tabs = delta.bytes().all(|byte| byte == b'\t');
+            let is_pure_spaces = delta.bytes().all(|byte| byte == b' ');
+
+            if !delta.is_empty() && is_pure_tabs {
+                tab_votes += 1;
+            } else if !delta.is_empty() && delta.len() <= 8 && is_pure_spaces {
+                // `delta` is a pure run of one whitespace kind, so `from_str` classifies it
+                // exactly as that kind — reuse it instead of re-deriving the space width here.
+                if let IndentStyle::Spaces(width) = IndentStyle::from_str(delta) {
+                    votes[usize::from(width)] += 1;
+                }
+            }
+        }
+        previous_indent = indent;
+    }
+
+    let (best_width, &best_space_votes) = votes
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, count)| **count)
+        .unwrap();
+
+    if tab_votes > best_space_votes {
+        IndentStyle::Tabs
+    } else if best_space_votes > tab_votes {
+        IndentStyle::Spaces(best_width as u8)
+    } else {
+        default
+    }
+}
+
+/// Detect a file's dominant indentation style, following the approach used by Helix's
+/// `auto_detect_indent_style`: for each line, compute the leading-whitespace delta relative
+/// to the previous line, and histogram whether that increment is a tab or an N-space group.
+/// The most frequent increment wins; a tie between tabs and the most frequent space width
+/// falls back to `default`, and among equally frequent space widths the widest is chosen.
+pub(crate) fn detect_indent_style(lines: &[Line], default: IndentStyle) -> IndentStyle {
+    histogram_indent_style(
+        lines
+            .iter()
+            .map(|line| (line.trim().is_empty(), leading_space(line))),
+        default,
+    )
+}
+
+/// Resolve the effective `indent-style` for a file, expanding `IndentationStyle::Detect`
+/// into a concrete `Spaces`/`Tabs` decision based on the file's dominant indentation.
+pub(crate) fn resolve_indent_style(setting: IndentationStyle, lines: &[Line]) -> IndentationStyle {
+    match setting {
+        IndentationStyle::Detect => match detect_indent_style(lines, IndentStyle::default()) {
+            IndentStyle::Tabs => IndentationStyle::Tabs,
+            IndentStyle::Spaces(_) => IndentationStyle::Spaces,
+        },
+        style => style,
+    }
+}
+
+/// Return the byte offset of the first character in `indent` at which the indentation
+/// switches from spaces to tabs or from tabs to spaces, if any.
+fn mixed_indent_boundary(indent: &str) -> Option<usize> {
+    let mut chars = indent.char_indices();
+    let (_, mut previous) = chars.next()?;
+
+    for (index, char) in chars {
+        if char != previous {
+            return Some(index);
+        }
+        previous = char;
+    }
+
+    None
+}
 
-        let string_range_index = string_ranges.binary_search_by(|range| {
-            if tab_offset < range.start() {
+/// Return `true` if `offset` falls within one of the file's multi-line strings.
+fn is_in_string_range(offset: TextSize, string_ranges: &[TextRange]) -> bool {
+    string_ranges
+        .binary_search_by(|range| {
+            if offset < range.start() {
                 std::cmp::Ordering::Greater
-            } else if range.contains(tab_offset) {
+            } else if range.contains(offset) {
                 std::cmp::Ordering::Equal
             } else {
                 std::cmp::Ordering::Less
             }
-        });
+        })
+        .is_ok()
+}
+
+/// Expand the leading whitespace of an indentation run to `indent_width`-wide spaces,
+/// preserving the visual column of each character rather than replacing every tab with a
+/// flat number of spaces. A zero `indent_width` has no well-defined column width, so the
+/// indentation is returned unchanged rather than dividing by zero.
+fn expand_indent(indent: &str, indent_width: u8) -> String {
+    if indent_width == 0 {
+        return indent.to_string();
+    }
+
+    let indent_width = usize::from(indent_width);
+    let mut expanded = String::with_capacity(indent.len());
+    let mut column = 0;
+
+    for char in indent.chars() {
+        if char == '\t' {
+            let width = indent_width - (column % indent_width);
+            expanded.push_str(&" ".repeat(width));
+            column += width;
+        } else {
+            expanded.push(char);
+            column += 1;
+        }
+    }
+
+    expanded
+}
+
+/// Contract the leading whitespace of an indentation run to tabs, replacing each
+/// `indent_width`-wide group of spaces with a single tab. Spaces left over once no further
+/// full group is available are preserved literally rather than dropped, so that indentation
+/// whose width isn't an exact multiple of `indent_width` keeps its original column width. A
+/// zero `indent_width` has no well-defined group size, so the indentation is returned
+/// unchanged rather than dividing by zero.
+fn contract_indent(indent: &str, indent_width: u8) -> String {
+    if indent_width == 0 {
+        return indent.to_string();
+    }
 
-        // If the tab character is within a multi-line string, abort.
-        if string_range_index.is_ok() {
-            None
+    let indent_width = usize::from(indent_width);
+    let mut contracted = String::with_capacity(indent.len());
+    let mut pending_spaces = 0;
+
+    for char in indent.chars() {
+        if char == ' ' {
+            pending_spaces += 1;
+            if pending_spaces == indent_width {
+                contracted.push('\t');
+                pending_spaces = 0;
+            }
         } else {
-            Some(Diagnostic::new(
-                TabIndentation,
-                TextRange::at(line.start(), indent.text_len()),
-            ))
+            contracted.push_str(&" ".repeat(pending_spaces));
+            pending_spaces = 0;
+            contracted.push(char);
         }
+    }
+
+    contracted.push_str(&" ".repeat(pending_spaces));
+    contracted
+}
+
+/// W191
+pub(crate) fn tab_indentation(
+    line: &Line,
+    string_ranges: &[TextRange],
+    indent_width: u8,
+    indent_style: IndentationStyle,
+) -> Option<Diagnostic> {
+    if indent_style != IndentationStyle::Spaces {
+        return None;
+    }
+
+    let indent = leading_space(line);
+
+    // A line whose indentation mixes tabs and spaces is already reported, more precisely, by
+    // `mixed_tabs_and_spaces`; don't also flag it here over the same range.
+    if mixed_indent_boundary(indent).is_some() {
+        return None;
+    }
+
+    let tab_index = IndentStyle::Spaces(indent_width).first_deviation(indent)?;
+    let tab_offset = line.start() + TextSize::try_from(tab_index).unwrap();
+
+    // If the tab character is within a multi-line string, abort.
+    if is_in_string_range(tab_offset, string_ranges) {
+        return None;
+    }
+
+    let range = TextRange::at(line.start(), indent.text_len());
+    let mut diagnostic = Diagnostic::new(TabIndentation, range);
+    diagnostic.set_fix(Fix::automatic(Edit::range_replacement(
+        IndentStyle::Spaces(indent_width).normalize(indent, indent_width),
+        range,
+    )));
+    Some(diagnostic)
+}
+
+/// Inverse of W191: flag spaces in indentation when `indent-style` is `tabs`.
+pub(crate) fn space_indentation(
+    line: &Line,
+    string_ranges: &[TextRange],
+    indent_width: u8,
+    indent_style: IndentationStyle,
+) -> Option<Diagnostic> {
+    if indent_style != IndentationStyle::Tabs {
+        return None;
+    }
+
+    let indent = leading_space(line);
+
+    // A line whose indentation mixes tabs and spaces is already reported, more precisely, by
+    // `mixed_tabs_and_spaces`; don't also flag it here over the same range.
+    if mixed_indent_boundary(indent).is_some() {
+        return None;
+    }
+
+    let space_index = IndentStyle::Tabs.first_deviation(indent)?;
+    let space_offset = line.start() + TextSize::try_from(space_index).unwrap();
+
+    // If the space character is within a multi-line string, abort.
+    if is_in_string_range(space_offset, string_ranges) {
+        return None;
+    }
+
+    let range = TextRange::at(line.start(), indent.text_len());
+    let mut diagnostic = Diagnostic::new(SpaceIndentation, range);
+    diagnostic.set_fix(Fix::automatic(Edit::range_replacement(
+        IndentStyle::Tabs.normalize(indent, indent_width),
+        range,
+    )));
+    Some(diagnostic)
+}
+
+/// Flag a single line's indentation switching between spaces and tabs, which the interpreter
+/// actually rejects with a `TabError`, rather than merely reporting the presence of any tab.
+pub(crate) fn mixed_tabs_and_spaces(
+    line: &Line,
+    string_ranges: &[TextRange],
+    indent_width: u8,
+    indent_style: IndentationStyle,
+) -> Option<Diagnostic> {
+    let indent = leading_space(line);
+    let boundary = mixed_indent_boundary(indent)?;
+    let boundary_offset = line.start() + TextSize::try_from(boundary).unwrap();
+
+    // If the style switch is within a multi-line string, abort.
+    if is_in_string_range(boundary_offset, string_ranges) {
+        return None;
+    }
+
+    let range = TextRange::at(line.start(), indent.text_len());
+    let mut diagnostic = Diagnostic::new(
+        MixedSpacesAndTabs,
+        TextRange::new(boundary_offset, range.end()),
+    );
+
+    let target = if indent_style == IndentationStyle::Tabs {
+        IndentStyle::Tabs
     } else {
-        None
+        IndentStyle::Spaces(indent_width)
+    };
+    diagnostic.set_fix(Fix::automatic(Edit::range_replacement(
+        target.normalize(indent, indent_width),
+        range,
+    )));
+    Some(diagnostic)
+}
+
+/// Run every indentation check (`TabIndentation`, `SpaceIndentation`, `MixedSpacesAndTabs`)
+/// over each line of a file. `IndentationStyle::Detect` is resolved via `resolve_indent_style`
+/// once for the whole file, rather than re-detecting it for every line.
+///
+/// A line whose indentation mixes tabs and spaces is only ever reported once, by
+/// `mixed_tabs_and_spaces`: `tab_indentation` and `space_indentation` both defer to it instead
+/// of also firing over the same range, since `MixedSpacesAndTabs` is the more specific diagnosis
+/// (and the two single-style fixes would otherwise conflict, each rewriting the same range).
+pub(crate) fn check_indentation(
+    lines: &[Line],
+    string_ranges: &[TextRange],
+    indent_width: u8,
+    indent_style: IndentationStyle,
+) -> Vec<Diagnostic> {
+    let indent_style = resolve_indent_style(indent_style, lines);
+
+    lines
+        .iter()
+        .flat_map(|line| {
+            [
+                tab_indentation(line, string_ranges, indent_width, indent_style),
+                space_indentation(line, string_ranges, indent_width, indent_style),
+                mixed_tabs_and_spaces(line, string_ranges, indent_width, indent_style),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_python_ast::newlines::UniversalNewlineIterator;
+    use ruff_text_size::TextSize;
+
+    use super::{
+        check_indentation, contract_indent, expand_indent, histogram_indent_style,
+        mixed_indent_boundary, IndentStyle, IndentationStyle, Line,
+    };
+
+    #[test]
+    fn expand_indent_aligns_tabs_to_columns() {
+        assert_eq!(expand_indent("\t", 4), "    ");
+        assert_eq!(expand_indent(" \t", 4), "    ");
+        assert_eq!(expand_indent("  \t", 4), "    ");
+        assert_eq!(expand_indent("\t\t", 4), "        ");
+        assert_eq!(expand_indent("\t", 8), "        ");
+    }
+
+    #[test]
+    fn expand_indent_leaves_non_tab_indentation_untouched() {
+        assert_eq!(expand_indent("    ", 4), "    ");
+        assert_eq!(expand_indent("", 4), "");
+    }
+
+    #[test]
+    fn expand_indent_is_a_no_op_for_zero_width() {
+        assert_eq!(expand_indent("\t\t", 0), "\t\t");
+    }
+
+    #[test]
+    fn contract_indent_groups_full_width_spaces_into_tabs() {
+        assert_eq!(contract_indent("    ", 4), "\t");
+        assert_eq!(contract_indent("        ", 4), "\t\t");
+    }
+
+    #[test]
+    fn contract_indent_preserves_a_partial_trailing_group_as_spaces() {
+        // 5 spaces: one full 4-wide group plus a leftover column that must not be dropped.
+        assert_eq!(contract_indent("     ", 4), "\t ");
+        // 2 spaces: no full group yet, so the indentation must not be deleted entirely.
+        assert_eq!(contract_indent("  ", 4), "  ");
+    }
+
+    #[test]
+    fn contract_indent_is_a_no_op_for_zero_width() {
+        assert_eq!(contract_indent("    ", 0), "    ");
+    }
+
+    #[test]
+    fn histogram_indent_style_detects_tabs() {
+        let indents = [(true, ""), (false, "\t"), (false, "\t\t"), (false, "\t")];
+        assert_eq!(
+            histogram_indent_style(indents.into_iter(), IndentStyle::default()),
+            IndentStyle::Tabs
+        );
+    }
+
+    #[test]
+    fn histogram_indent_style_detects_dominant_space_width() {
+        let indents = [(true, ""), (false, "  "), (false, "    "), (false, "  ")];
+        assert_eq!(
+            histogram_indent_style(indents.into_iter(), IndentStyle::default()),
+            IndentStyle::Spaces(2)
+        );
+    }
+
+    #[test]
+    fn histogram_indent_style_ignores_blank_lines_between_same_depth_code() {
+        // A blank line between two lines at the same depth must not be treated as a
+        // standalone delta against "" — otherwise it would inject a bogus vote for "    ".
+        // With the blank lines skipped, there's one 4-space and one tab increment, a tie that
+        // falls back to `default`; the two bogus votes would instead make spaces win.
+        let indents = [
+            (false, "    "),
+            (true, ""),
+            (false, "    "),
+            (true, ""),
+            (false, "    "),
+            (false, "    \t"),
+        ];
+        assert_eq!(
+            histogram_indent_style(indents.into_iter(), IndentStyle::Tabs),
+            IndentStyle::Tabs
+        );
+    }
+
+    #[test]
+    fn histogram_indent_style_resets_after_a_non_blank_zero_indent_line() {
+        // Two top-level `def`s, each with a body at a different width. Both top-level lines
+        // have a `""` indent but are NOT blank, so `previous_indent` must reset to `""` at
+        // each one rather than staying stale at the previous body's depth — otherwise the
+        // second body's indent gets diffed against the first body's indent instead of `""`,
+        // and its true width is never counted.
+        let indents = [
+            (false, ""),
+            (false, "    "),
+            (false, ""),
+            (false, "        "),
+            (false, ""),
+            (false, "        "),
+        ];
+        assert_eq!(
+            histogram_indent_style(indents.into_iter(), IndentStyle::default()),
+            IndentStyle::Spaces(8)
+        );
+    }
+
+    #[test]
+    fn histogram_indent_style_falls_back_to_default_on_tie() {
+        // One tab-increment and one 4-space-increment: an even split falls back to `default`.
+        let indents = [(false, "\t"), (false, "\t    "), (false, "\t")];
+        assert_eq!(
+            histogram_indent_style(indents.into_iter(), IndentStyle::Tabs),
+            IndentStyle::Tabs
+        );
+    }
+
+    #[test]
+    fn mixed_indent_boundary_finds_spaces_then_tab() {
+        assert_eq!(mixed_indent_boundary("  \t"), Some(2));
+    }
+
+    #[test]
+    fn mixed_indent_boundary_finds_tab_then_spaces() {
+        assert_eq!(mixed_indent_boundary("\t  "), Some(1));
+    }
+
+    #[test]
+    fn mixed_indent_boundary_is_none_for_a_single_style() {
+        assert_eq!(mixed_indent_boundary("    "), None);
+        assert_eq!(mixed_indent_boundary("\t\t"), None);
+        assert_eq!(mixed_indent_boundary(""), None);
+    }
+
+    #[test]
+    fn indent_style_from_str_detects_tabs() {
+        assert_eq!(IndentStyle::from_str("\t"), IndentStyle::Tabs);
+        // Any tab in the sample means tabs, even alongside spaces.
+        assert_eq!(IndentStyle::from_str("  \t"), IndentStyle::Tabs);
+    }
+
+    #[test]
+    fn indent_style_from_str_detects_space_width() {
+        assert_eq!(IndentStyle::from_str("  "), IndentStyle::Spaces(2));
+        assert_eq!(IndentStyle::from_str("    "), IndentStyle::Spaces(4));
+    }
+
+    #[test]
+    fn indent_style_from_str_treats_empty_sample_as_one_space() {
+        assert_eq!(IndentStyle::from_str(""), IndentStyle::Spaces(1));
+    }
+
+    #[test]
+    fn check_indentation_defers_to_mixed_tabs_and_spaces_instead_of_double_reporting() {
+        let source = "if True:\n    pass\nif True:\n \tpass\n";
+        let lines: Vec<Line> =
+            UniversalNewlineIterator::with_offset(source, TextSize::default()).collect();
+
+        let diagnostics = check_indentation(&lines, &[], 4, IndentationStyle::Spaces);
+
+        // Only the last line's indentation (` \t`) deviates, and it mixes spaces and tabs —
+        // it must be reported exactly once, by `mixed_tabs_and_spaces`, rather than also
+        // tripping `tab_indentation` (it contains a tab) over the same range.
+        assert_eq!(diagnostics.len(), 1);
+        let mixed_line = &lines[3];
+        assert!(diagnostics[0].range().start() > mixed_line.start());
+    }
+
+    #[test]
+    fn check_indentation_still_reports_a_pure_tab_line() {
+        let source = "if True:\n\tpass\n";
+        let lines: Vec<Line> =
+            UniversalNewlineIterator::with_offset(source, TextSize::default()).collect();
+
+        let diagnostics = check_indentation(&lines, &[], 4, IndentationStyle::Spaces);
+
+        // A line indented with tabs alone (no mixing) still trips `tab_indentation`.
+        assert_eq!(diagnostics.len(), 1);
     }
 }